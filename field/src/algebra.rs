@@ -0,0 +1,150 @@
+//! An explicit algebraic trait hierarchy that sits alongside [`Field`], separating additive and
+//! multiplicative group structure from field structure.
+//!
+//! `Field` bundles "has a commutative additive group, a commutative multiplicative group on its
+//! nonzero elements, and a distributive law between them" into a single trait. Generic code that
+//! only needs one of those group structures — e.g. a multi-scalar sum, or a subgroup generator
+//! search — can instead depend on [`AbelianGroup`] directly: every `F: Field` is blanket-impl'd as
+//! a [`Group`]/[`AbelianGroup`] below (via its addition), so FRI/poly layers that currently demand
+//! `F: Field` just to get `+`/`-` can relax to `G: AbelianGroup` with no call-site changes, and
+//! third parties can implement `AbelianGroup` for a custom commitment domain without providing a
+//! full field.
+//!
+//! A field's multiplicative structure can't get the same direct treatment, since zero has no
+//! multiplicative inverse and `Group` has no room for an absorbing element — that's what the
+//! [`Multiplicative`] wrapper below is for. [`Additive`] is kept alongside it for symmetry, and
+//! for generic code that wants to name "the additive view of `F`" as an explicit type rather than
+//! relying on the blanket impl.
+
+use crate::types::Field;
+
+/// A set with an associative binary operation, an identity element, and inverses.
+pub trait Group: Sized + Copy + Eq {
+    const IDENTITY: Self;
+    fn op(&self, rhs: &Self) -> Self;
+    fn group_inverse(&self) -> Self;
+}
+
+/// A [`Group`] whose operation is commutative.
+pub trait AbelianGroup: Group {}
+
+/// The data that makes a type a finite field, split out of `Field` so generic code can depend on
+/// just this structural information instead of the full arithmetic surface.
+pub trait FiniteField: Sized {
+    /// The field's characteristic (the order of its prime subfield).
+    const CHARACTERISTIC: u64;
+
+    /// The largest `n` such that `2^n` divides the order of the multiplicative group.
+    fn two_adicity() -> usize;
+}
+
+/// Every field is an abelian group under addition, with `self` itself standing in for the group
+/// element — no wrapper needed, unlike [`Multiplicative`].
+impl<F: Field> Group for F {
+    const IDENTITY: Self = F::ZERO;
+
+    fn op(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    fn group_inverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<F: Field> AbelianGroup for F {}
+
+/// A field's additive group, as a [`Group`]/[`AbelianGroup`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Additive<F>(pub F);
+
+impl<F: Field> Group for Additive<F> {
+    const IDENTITY: Self = Additive(F::ZERO);
+
+    fn op(&self, rhs: &Self) -> Self {
+        Additive(self.0 + rhs.0)
+    }
+
+    fn group_inverse(&self) -> Self {
+        Additive(-self.0)
+    }
+}
+
+impl<F: Field> AbelianGroup for Additive<F> {}
+
+/// A field's multiplicative group of nonzero elements, as a [`Group`]/[`AbelianGroup`]. Following
+/// this crate's `div_unsafe`/`inverse` convention elsewhere, `group_inverse` on the zero element
+/// returns zero rather than panicking; callers that need a true group (no absorbing zero element)
+/// should restrict themselves to nonzero inputs.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Multiplicative<F>(pub F);
+
+impl<F: Field> Group for Multiplicative<F> {
+    const IDENTITY: Self = Multiplicative(F::ONE);
+
+    fn op(&self, rhs: &Self) -> Self {
+        Multiplicative(self.0 * rhs.0)
+    }
+
+    fn group_inverse(&self) -> Self {
+        Multiplicative(self.0.try_inverse().unwrap_or(F::ZERO))
+    }
+}
+
+impl<F: Field> AbelianGroup for Multiplicative<F> {}
+
+impl<F: Field> FiniteField for F {
+    const CHARACTERISTIC: u64 = F::CHARACTERISTIC;
+
+    fn two_adicity() -> usize {
+        F::TWO_ADICITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crandall_field::CrandallField;
+
+    #[test]
+    fn test_additive_group_laws() {
+        type F = CrandallField;
+        let a = Additive(F::rand());
+        let b = Additive(F::rand());
+
+        assert_eq!(a.op(&Additive::IDENTITY), a);
+        assert_eq!(a.op(&a.group_inverse()), Additive::IDENTITY);
+        assert_eq!(a.op(&b), b.op(&a));
+    }
+
+    #[test]
+    fn test_multiplicative_group_laws() {
+        type F = CrandallField;
+        let a = Multiplicative(F::rand());
+        let b = Multiplicative(F::rand());
+
+        assert_eq!(a.op(&Multiplicative::IDENTITY), a);
+        assert_eq!(a.op(&a.group_inverse()), Multiplicative::IDENTITY);
+        assert_eq!(a.op(&b), b.op(&a));
+    }
+
+    #[test]
+    fn test_multiplicative_group_inverse_of_zero_is_zero() {
+        type F = CrandallField;
+        let zero = Multiplicative(F::ZERO);
+        assert_eq!(zero.group_inverse(), zero);
+    }
+
+    /// A stand-in for an FRI/poly layer that only needs additive group structure: it should
+    /// accept a bare `F: Field` with no `Additive` wrapping, via the blanket impl above.
+    fn sum_via_abelian_group<G: AbelianGroup>(xs: &[G]) -> G {
+        xs.iter().fold(G::IDENTITY, |acc, x| acc.op(x))
+    }
+
+    #[test]
+    fn test_field_satisfies_abelian_group_directly() {
+        type F = CrandallField;
+        let xs = F::rand_vec(4);
+        assert_eq!(sum_via_abelian_group(&xs), xs.iter().copied().sum());
+    }
+}
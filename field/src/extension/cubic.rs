@@ -0,0 +1,186 @@
+use core::fmt::{self, Display, Formatter};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num::bigint::BigUint;
+use rand::Rng;
+
+use crate::extension::{Extendable, FieldExtension, Frobenius, OEF};
+use crate::ops::Square;
+use crate::types::{Field, Sample};
+
+/// `GF(p)[x]/(x^3 - W)`, the cubic extension of `F` used to reach ~192-bit FRI soundness over
+/// Goldilocks at a cheaper rate than `QuarticExtension`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct CubicExtension<F: Extendable<3>>(pub(crate) [F; 3]);
+
+impl<F: Extendable<3>> OEF<3> for CubicExtension<F> {
+    const W: F = F::W;
+    const DTH_ROOT: F = F::DTH_ROOT;
+}
+
+impl<F: Extendable<3>> Frobenius<3> for CubicExtension<F> {}
+
+impl<F: Extendable<3>> FieldExtension<3> for CubicExtension<F> {
+    type BaseField = F;
+
+    fn to_basefield_array(&self) -> [F; 3] {
+        self.0
+    }
+
+    fn from_basefield_array(arr: [F; 3]) -> Self {
+        Self(arr)
+    }
+
+    fn is_in_basefield(&self) -> bool {
+        self.0[1].is_zero() && self.0[2].is_zero()
+    }
+}
+
+impl<F: Extendable<3>> From<F> for CubicExtension<F> {
+    fn from(x: F) -> Self {
+        Self([x, F::ZERO, F::ZERO])
+    }
+}
+
+impl<F: Extendable<3>> Sample for CubicExtension<F> {
+    fn sample<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self([F::sample(rng), F::sample(rng), F::sample(rng)])
+    }
+}
+
+impl<F: Extendable<3>> Field for CubicExtension<F> {
+    type PrimeField = F;
+
+    const ZERO: Self = Self([F::ZERO; 3]);
+    const ONE: Self = Self([F::ONE, F::ZERO, F::ZERO]);
+    const TWO: Self = Self([F::TWO, F::ZERO, F::ZERO]);
+    const NEG_ONE: Self = Self([F::NEG_ONE, F::ZERO, F::ZERO]);
+
+    const CHARACTERISTIC: u64 = F::CHARACTERISTIC;
+
+    // `p^3 - 1`'s power-of-two part is unaffected by the extension, since `p^2 + p + 1` is odd.
+    const TWO_ADICITY: usize = F::TWO_ADICITY;
+
+    const MULTIPLICATIVE_GROUP_GENERATOR: Self = Self(F::EXT_MULTIPLICATIVE_GROUP_GENERATOR);
+    const POWER_OF_TWO_GENERATOR: Self = Self(F::EXT_POWER_OF_TWO_GENERATOR);
+
+    fn order() -> BigUint {
+        F::order() * F::order() * F::order()
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        // `m = Frob(x) * Frob^2(x)` lies one short of the full norm; `N = x * m` lands in the
+        // base field (the norm map), so `1/x = m / N` needs only a single base-field inversion.
+        let frob1 = self.frobenius();
+        let frob2 = frob1.frobenius();
+        let m = frob1 * frob2;
+        let norm = (*self * m).to_basefield_array()[0];
+        debug_assert!((*self * m).is_in_basefield());
+
+        Some(m.scalar_mul(norm.inverse()))
+    }
+
+    fn from_canonical_u64(n: u64) -> Self {
+        F::from_canonical_u64(n).into()
+    }
+
+    fn from_noncanonical_u128(n: u128) -> Self {
+        F::from_noncanonical_u128(n).into()
+    }
+}
+
+impl<F: Extendable<3>> CubicExtension<F> {
+    fn scalar_mul(self, scalar: F) -> Self {
+        let Self([a0, a1, a2]) = self;
+        Self([a0 * scalar, a1 * scalar, a2 * scalar])
+    }
+}
+
+impl<F: Extendable<3>> Display for CubicExtension<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}*a + {}*a^2", self.0[0], self.0[1], self.0[2])
+    }
+}
+
+impl<F: Extendable<3>> Neg for CubicExtension<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let Self([a0, a1, a2]) = self;
+        Self([-a0, -a1, -a2])
+    }
+}
+
+impl<F: Extendable<3>> Add for CubicExtension<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let Self([a0, a1, a2]) = self;
+        let Self([b0, b1, b2]) = rhs;
+        Self([a0 + b0, a1 + b1, a2 + b2])
+    }
+}
+
+impl<F: Extendable<3>> AddAssign for CubicExtension<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: Extendable<3>> Sum for CubicExtension<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<F: Extendable<3>> Sub for CubicExtension<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<F: Extendable<3>> SubAssign for CubicExtension<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: Extendable<3>> Mul for CubicExtension<F> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let Self([a0, a1, a2]) = self;
+        let Self([b0, b1, b2]) = rhs;
+        let w = <Self as OEF<3>>::W;
+
+        // Schoolbook multiplication of two degree-2 polynomials, reduced by `x^3 = W`: the
+        // would-be `x^3` and `x^4` terms fold back into the low coefficients scaled by `W`.
+        let c0 = a0 * b0 + w * (a1 * b2 + a2 * b1);
+        let c1 = a0 * b1 + a1 * b0 + w * a2 * b2;
+        let c2 = a0 * b2 + a1 * b1 + a2 * b0;
+        Self([c0, c1, c2])
+    }
+}
+
+impl<F: Extendable<3>> MulAssign for CubicExtension<F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F: Extendable<3>> Product for CubicExtension<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<F: Extendable<3>> Div for CubicExtension<F> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse()
+    }
+}
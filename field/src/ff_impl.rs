@@ -0,0 +1,207 @@
+//! Implementations of the `ff`/`group` ecosystem traits for [`GoldilocksField`] and its
+//! extensions, so Goldilocks can be dropped into circuits and gadgets written against those
+//! traits (bellman, halo2, and the wider `ff`-based ZK stack) without a wrapper newtype. This is
+//! the same migration `librustzcash`/bellman did when `Fr` moved onto the shared `ff`/`group`
+//! traits.
+//!
+//! Gated behind the `ff-traits` feature, so crates that don't need the interop keep their
+//! existing dependency graph. The feature itself, and the `ff`/`subtle`/`rand` dependencies it
+//! needs, must be declared in this crate's `Cargo.toml` before `--features ff-traits` will build;
+//! that manifest isn't part of this tree yet, so wiring it up is left to whoever adds one.
+#![cfg(feature = "ff-traits")]
+
+use ff::{Field as FfField, PrimeField, PrimeFieldBits};
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::extension::quadratic::QuadraticExtension;
+use crate::extension::quartic::QuarticExtension;
+use crate::extension::quintic::QuinticExtension;
+use crate::goldilocks_field::GoldilocksField;
+use crate::sqrt::Sqrt;
+use crate::types::Field;
+
+impl ConstantTimeEq for GoldilocksField {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_canonical_u64().ct_eq(&other.to_canonical_u64())
+    }
+}
+
+impl ConditionallySelectable for GoldilocksField {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::from_canonical_u64(u64::conditional_select(
+            &a.to_canonical_u64(),
+            &b.to_canonical_u64(),
+            choice,
+        ))
+    }
+}
+
+impl FfField for GoldilocksField {
+    const ZERO: Self = <Self as Field>::ZERO;
+    const ONE: Self = <Self as Field>::ONE;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        // `% ORDER` would be biased, since `ORDER` doesn't divide `2^64`; reject and resample
+        // instead of accepting that skew.
+        loop {
+            let value = rng.next_u64();
+            if value < Self::ORDER {
+                return Self::from_canonical_u64(value);
+            }
+        }
+    }
+
+    fn square(&self) -> Self {
+        *self * *self
+    }
+
+    fn double(&self) -> Self {
+        *self + *self
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        match self.try_inverse() {
+            Some(inv) => CtOption::new(inv, Choice::from(1)),
+            None => CtOption::new(Self::ZERO, Choice::from(0)),
+        }
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        // `ff::Field::sqrt_ratio` must return `(Choice::from(0), Self::ZERO)` for a zero divisor
+        // rather than panicking, so this goes through `try_inverse` instead of `.inverse()`.
+        match div.try_inverse() {
+            Some(div_inv) => {
+                let sqrt = (*num * div_inv).sqrt();
+                (
+                    Choice::from(sqrt.is_some() as u8),
+                    sqrt.unwrap_or(Self::ZERO),
+                )
+            }
+            None => (Choice::from(0), Self::ZERO),
+        }
+    }
+}
+
+impl PrimeField for GoldilocksField {
+    type Repr = [u8; 8];
+
+    // p = 2^64 - 2^32 + 1.
+    const MODULUS: &'static str = "0xffffffff00000001";
+    const NUM_BITS: u32 = 64;
+    const CAPACITY: u32 = 63;
+    // TWO_INV = (p + 1) / 2.
+    const TWO_INV: Self = Self(9223372034707292161);
+    const MULTIPLICATIVE_GENERATOR: Self = Self::MULTIPLICATIVE_GROUP_GENERATOR;
+    const S: u32 = Self::TWO_ADICITY as u32;
+    const ROOT_OF_UNITY: Self = Self::POWER_OF_TWO_GENERATOR;
+    // ROOT_OF_UNITY_INV = ROOT_OF_UNITY^-1, verifiable in Sage with
+    // `pow(1753635133440165772, -1, 2**64 - 2**32 + 1)`.
+    const ROOT_OF_UNITY_INV: Self = Self(8554224884056360729);
+    // DELTA = MULTIPLICATIVE_GENERATOR^(2^S), distinct from ROOT_OF_UNITY = GENERATOR^((p-1)>>S).
+    // Verifiable in Sage with `pow(7, 2**32, 2**64 - 2**32 + 1)`.
+    const DELTA: Self = Self(12275445934081160404);
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let value = u64::from_le_bytes(repr);
+        CtOption::new(
+            Self::from_canonical_u64(value % Self::ORDER),
+            Choice::from((value < Self::ORDER) as u8),
+        )
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.to_canonical_u64().to_le_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from((self.to_canonical_u64() & 1) as u8)
+    }
+}
+
+impl PrimeFieldBits for GoldilocksField {
+    type ReprBits = [u64; 1];
+
+    fn to_le_bits(&self) -> ff::FieldBits<Self::ReprBits> {
+        ff::FieldBits::new([self.to_canonical_u64()])
+    }
+
+    fn char_le_bits() -> ff::FieldBits<Self::ReprBits> {
+        ff::FieldBits::new([Self::ORDER])
+    }
+}
+
+macro_rules! impl_ff_field_for_extension {
+    ($ext:ty, $deg:literal) => {
+        impl FfField for $ext {
+            const ZERO: Self = <Self as Field>::ZERO;
+            const ONE: Self = <Self as Field>::ONE;
+
+            fn random(mut rng: impl RngCore) -> Self {
+                let mut coeffs = [GoldilocksField::ZERO; $deg];
+                for c in coeffs.iter_mut() {
+                    *c = GoldilocksField::random(&mut rng);
+                }
+                Self::from_basefield_array(coeffs)
+            }
+
+            fn square(&self) -> Self {
+                *self * *self
+            }
+
+            fn double(&self) -> Self {
+                *self + *self
+            }
+
+            fn invert(&self) -> CtOption<Self> {
+                match self.try_inverse() {
+                    Some(inv) => CtOption::new(inv, Choice::from(1)),
+                    None => CtOption::new(Self::ZERO, Choice::from(0)),
+                }
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+                // See the base-field impl: must not panic on a zero divisor.
+                match div.try_inverse() {
+                    Some(div_inv) => {
+                        let sqrt = (*num * div_inv).sqrt();
+                        (
+                            Choice::from(sqrt.is_some() as u8),
+                            sqrt.unwrap_or(Self::ZERO),
+                        )
+                    }
+                    None => (Choice::from(0), Self::ZERO),
+                }
+            }
+        }
+
+        impl ConstantTimeEq for $ext {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                // Lane-wise, ANDing every coefficient's `Choice` together, rather than `*self ==
+                // *other`, which would branch on the (potentially secret) coefficients.
+                let a = self.to_basefield_array();
+                let b = other.to_basefield_array();
+                a.iter()
+                    .zip(&b)
+                    .fold(Choice::from(1), |acc, (x, y)| acc & x.ct_eq(y))
+            }
+        }
+
+        impl ConditionallySelectable for $ext {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                // Lane-wise `conditional_select`, rather than branching on `choice.into()`.
+                let a = a.to_basefield_array();
+                let b = b.to_basefield_array();
+                let mut out = [GoldilocksField::ZERO; $deg];
+                for i in 0..$deg {
+                    out[i] = GoldilocksField::conditional_select(&a[i], &b[i], choice);
+                }
+                Self::from_basefield_array(out)
+            }
+        }
+    };
+}
+
+impl_ff_field_for_extension!(QuadraticExtension<GoldilocksField>, 2);
+impl_ff_field_for_extension!(QuarticExtension<GoldilocksField>, 4);
+impl_ff_field_for_extension!(QuinticExtension<GoldilocksField>, 5);
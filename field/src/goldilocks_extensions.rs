@@ -1,3 +1,4 @@
+use crate::extension::cubic::CubicExtension;
 use crate::extension::quadratic::QuadraticExtension;
 use crate::extension::quartic::QuarticExtension;
 use crate::extension::quintic::QuinticExtension;
@@ -7,6 +8,27 @@ use crate::types::Field;
 
 impl Frobenius<1> for GoldilocksField {}
 
+impl Extendable<3> for GoldilocksField {
+    type Extension = CubicExtension<Self>;
+
+    // Verifiable in Sage with
+    // `R.<x> = GF(p)[]; assert (x^3 - 2).is_irreducible()`.
+    const W: Self = Self(2);
+
+    // DTH_ROOT = W^((ORDER - 1)/3)
+    const DTH_ROOT: Self = Self(4294967295);
+
+    // A generator of the full multiplicative group of order `p^3 - 1`, found by brute-force
+    // search and verified in Sage with
+    // `R.<x> = GF(p)[]; K.<a> = GF(p^3, modulus=x^3-2); (a^2*3 + a).multiplicative_order() == p^3 - 1`.
+    const EXT_MULTIPLICATIVE_GROUP_GENERATOR: [Self; 3] = [Self(0), Self(1), Self(3)];
+
+    // Since `p^2 + p + 1` (the cofactor of `p^3 - 1` beyond the base field's order) is odd, the
+    // whole 2-Sylow subgroup of `GF(p^3)*` already lives inside the base field, so this is just
+    // the base field's own generator embedded in the constant term.
+    const EXT_POWER_OF_TWO_GENERATOR: [Self; 3] = [Self::POWER_OF_TWO_GENERATOR, Self(0), Self(0)];
+}
+
 impl Extendable<2> for GoldilocksField {
     type Extension = QuadraticExtension<Self>;
 
@@ -0,0 +1,246 @@
+use core::fmt::Debug;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::extension::Extendable;
+use crate::types::Field;
+
+/// `WIDTH` copies of a field element packed into a single SIMD register, with element-wise
+/// arithmetic that runs as one instruction on targets that support it (and falls back to `WIDTH`
+/// scalar ops elsewhere). This is the `AbstractField`/`PackedField` split from Plonky3's
+/// `p3-field`: NTT and Merkle-leaf hashing loops hold their accumulator as a `PackedField` and
+/// only unpack at the edges, rather than looping over `&[F]` directly.
+pub trait PackedField:
+    'static
+    + Copy
+    + Clone
+    + Debug
+    + Default
+    + From<Self::Scalar>
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Neg<Output = Self>
+    + Sum
+    + Product
+    + Eq
+    + PartialEq
+    + Send
+    + Sync
+{
+    type Scalar: Field;
+
+    const WIDTH: usize;
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Packs `Self::WIDTH` scalars into one `Self`.
+    fn from_slice(slice: &[Self::Scalar]) -> Self;
+
+    /// The `Self::WIDTH` scalars this value packs, in lane order.
+    fn as_slice(&self) -> &[Self::Scalar];
+
+    fn as_slice_mut(&mut self) -> &mut [Self::Scalar];
+
+    /// Packs the same scalar into every lane.
+    fn broadcast(x: Self::Scalar) -> Self;
+
+    /// Packs `input` into `Self`s, `Self::WIDTH` scalars at a time.
+    ///
+    /// # Panics
+    /// Panics if `input.len()` is not a multiple of `Self::WIDTH`.
+    fn pack(input: &[Self::Scalar]) -> Vec<Self> {
+        assert_eq!(input.len() % Self::WIDTH, 0);
+        input.chunks_exact(Self::WIDTH).map(Self::from_slice).collect()
+    }
+
+    /// The inverse of `pack`: flattens packed values back out to scalars.
+    fn unpack(packed: &[Self]) -> Vec<Self::Scalar> {
+        packed.iter().flat_map(|p| p.as_slice().to_vec()).collect()
+    }
+}
+
+/// A portable fallback `PackedField` of width 1, used on targets without a dedicated SIMD
+/// backend so code written against `PackedField` keeps compiling everywhere.
+///
+/// This wraps `F` in a newtype rather than implementing `PackedField` (and the arithmetic traits
+/// it requires) directly on `[F; 1]`: Rust's orphan rules forbid implementing foreign traits like
+/// `Add` for a bare array, since arrays have no local type ahead of the uncovered `F`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Portable<F>(pub F);
+
+impl<F: Field> From<F> for Portable<F> {
+    fn from(x: F) -> Self {
+        Self(x)
+    }
+}
+
+impl<F: Field> Add for Portable<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+impl<F: Field> AddAssign for Portable<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<F: Field> Sum for Portable<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(F::ZERO), |a, b| a + b)
+    }
+}
+
+impl<F: Field> Sub for Portable<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+impl<F: Field> SubAssign for Portable<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: Field> Mul for Portable<F> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 * rhs.0)
+    }
+}
+impl<F: Field> MulAssign for Portable<F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl<F: Field> Product for Portable<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(F::ONE), |a, b| a * b)
+    }
+}
+
+impl<F: Field> Neg for Portable<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<F: Field> PackedField for Portable<F> {
+    type Scalar = F;
+
+    const WIDTH: usize = 1;
+    const ZERO: Self = Self(F::ZERO);
+    const ONE: Self = Self(F::ONE);
+
+    fn from_slice(slice: &[F]) -> Self {
+        Self(slice[0])
+    }
+
+    fn as_slice(&self) -> &[F] {
+        core::slice::from_ref(&self.0)
+    }
+
+    fn as_slice_mut(&mut self) -> &mut [F] {
+        core::slice::from_mut(&mut self.0)
+    }
+
+    fn broadcast(x: F) -> Self {
+        Self(x)
+    }
+}
+
+/// A `PackedField` whose scalar is `F`'s degree-`D` extension, packed coefficient-wise: lane `i`
+/// of the `j`-th of the `D` underlying packings holds coefficient `j` of the `i`-th packed
+/// extension element. This lets the NTT/FRI layers batch extension-field arithmetic the same way they
+/// batch base-field arithmetic, by packing each of the `D` coefficient arrays independently.
+pub trait PackedFieldExtension<const D: usize>: PackedField
+where
+    Self::Scalar: Extendable<D>,
+{
+    /// Packs `Self::WIDTH` extension elements, coefficient-wise, into `D` packed values (one per
+    /// coefficient).
+    fn from_ext_slice(
+        exts: &[<Self::Scalar as Extendable<D>>::Extension],
+    ) -> [Self; D];
+
+    /// The inverse of `from_ext_slice`.
+    fn to_ext_vec(packed: &[Self; D]) -> Vec<<Self::Scalar as Extendable<D>>::Extension>;
+}
+
+impl<P, const D: usize> PackedFieldExtension<D> for P
+where
+    P: PackedField,
+    P::Scalar: Extendable<D>,
+{
+    fn from_ext_slice(exts: &[<P::Scalar as Extendable<D>>::Extension]) -> [Self; D] {
+        use crate::extension::FieldExtension;
+
+        let width = exts.len();
+        let mut coeff_cols: Vec<Vec<P::Scalar>> = vec![Vec::with_capacity(width); D];
+        for ext in exts {
+            let coeffs = ext.to_basefield_array();
+            for (col, &c) in coeff_cols.iter_mut().zip(coeffs.iter()) {
+                col.push(c);
+            }
+        }
+        core::array::from_fn(|i| P::from_slice(&coeff_cols[i]))
+    }
+
+    fn to_ext_vec(packed: &[Self; D]) -> Vec<<P::Scalar as Extendable<D>>::Extension> {
+        use crate::extension::FieldExtension;
+
+        let cols: Vec<&[P::Scalar]> = packed.iter().map(|p| p.as_slice()).collect();
+        (0..P::WIDTH)
+            .map(|lane| {
+                let coeffs: [P::Scalar; D] = core::array::from_fn(|i| cols[i][lane]);
+                <P::Scalar as Extendable<D>>::Extension::from_basefield_array(coeffs)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crandall_field::CrandallField;
+
+    #[test]
+    fn test_portable_pack_roundtrip() {
+        type F = CrandallField;
+        let scalars: Vec<F> = (0..8).map(|_| F::rand()).collect();
+
+        let packed = Portable::<F>::pack(&scalars);
+        assert_eq!(Portable::<F>::unpack(&packed), scalars);
+    }
+
+    #[test]
+    fn test_portable_ops_match_scalar_ops() {
+        type F = CrandallField;
+        let a = F::rand();
+        let b = F::rand();
+
+        let pa = Portable::<F>::from_slice(&[a]);
+        let pb = Portable::<F>::from_slice(&[b]);
+
+        assert_eq!((pa + pb).as_slice(), &[a + b]);
+        assert_eq!((pa - pb).as_slice(), &[a - b]);
+        assert_eq!((pa * pb).as_slice(), &[a * b]);
+        assert_eq!((-pa).as_slice(), &[-a]);
+    }
+
+    #[test]
+    fn test_portable_ops_near_modulus() {
+        type F = CrandallField;
+        let near_zero = F::ZERO - F::ONE; // Wraps to the top of the field.
+        let pa = Portable::<F>::from_slice(&[near_zero]);
+        let pb = Portable::<F>::from_slice(&[F::ONE]);
+
+        assert_eq!((pa + pb).as_slice(), &[F::ZERO]);
+    }
+}
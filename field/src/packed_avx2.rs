@@ -0,0 +1,266 @@
+//! An AVX2 lane-of-4 [`PackedField`] backend for [`GoldilocksField`].
+//!
+//! Goldilocks's modulus `p = 2^64 - 2^32 + 1` reduces cheaply: a 128-bit product `lo + hi * 2^64`
+//! folds to `lo - hi_hi + hi_lo * (2^32 - 1)` (since `2^64 ≡ 2^32 - 1 (mod p)`, applied twice to
+//! `hi`'s own high and low 32-bit halves), which is itself below `2^65`: one more 64-bit
+//! overflow fold and a conditional subtract land it back in `[0, p)`. AVX2 has no native 64-bit compare or
+//! 64-bit multiply, so each lane's arithmetic is built out of 32-bit multiplies
+//! (`_mm256_mul_epu32`) and the sign-bit trick for unsigned 64-bit comparisons. `p > 2^63`, so
+//! even plain 64-bit adds of two canonical values can overflow and every partial-product add here
+//! is checked for that carry rather than assumed not to happen.
+#![cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+
+use core::arch::x86_64::*;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::goldilocks_field::GoldilocksField;
+use crate::packed::PackedField;
+use crate::types::Field;
+
+const WIDTH: usize = 4;
+const FIELD_ORDER: u64 = GoldilocksField::ORDER;
+const EPSILON: u64 = 0xFFFFFFFF;
+
+/// Four `GoldilocksField` elements packed into a single `__m256i`.
+#[derive(Clone, Copy)]
+pub struct PackedGoldilocksAvx2(pub __m256i);
+
+impl PackedGoldilocksAvx2 {
+    #[inline]
+    unsafe fn canonicalize(x: __m256i) -> __m256i {
+        let order = _mm256_set1_epi64x(FIELD_ORDER as i64);
+        // Unsigned 64-bit `>=` via the sign-flip trick: flip the top bit of both operands so
+        // AVX2's signed `_mm256_cmpgt_epi64` implements the unsigned comparison.
+        let flip = _mm256_set1_epi64x(i64::MIN);
+        let ge = _mm256_or_si256(
+            _mm256_cmpgt_epi64(_mm256_xor_si256(x, flip), _mm256_xor_si256(order, flip)),
+            _mm256_cmpeq_epi64(x, order),
+        );
+        _mm256_sub_epi64(x, _mm256_and_si256(ge, order))
+    }
+
+    #[inline]
+    unsafe fn add_no_double_overflow(a: __m256i, b: __m256i) -> __m256i {
+        // `a` and `b` are each canonical (< p), but `p > 2^63`, so their sum can itself overflow
+        // 64 bits (e.g. `(p-1) + (p-1)`). Detect that wraparound (unsigned sum < either operand)
+        // and fold the lost `2^64` back in via `2^64 ≡ 2^32 - 1 (mod p)` before the final reduce.
+        let ca = Self::canonicalize(a);
+        let cb = Self::canonicalize(b);
+        let sum = _mm256_add_epi64(ca, cb);
+        let flip = _mm256_set1_epi64x(i64::MIN);
+        let overflowed = _mm256_cmpgt_epi64(_mm256_xor_si256(ca, flip), _mm256_xor_si256(sum, flip));
+        let epsilon = _mm256_set1_epi64x(EPSILON as i64);
+        let sum = _mm256_add_epi64(sum, _mm256_and_si256(overflowed, epsilon));
+        Self::canonicalize(sum)
+    }
+
+    #[inline]
+    unsafe fn reduce128(lo: __m256i, hi: __m256i) -> __m256i {
+        // `lo + hi * 2^64 (mod p)`. Write `hi = hi_hi * 2^32 + hi_lo` and use `2^64 ≡ 2^32 - 1
+        // (mod p)` twice: `hi * 2^64 ≡ hi_lo * (2^32 - 1) - hi_hi (mod p)`. Unlike a plain
+        // `hi << 32`, this never discards `hi`'s top 32 bits, and `hi_lo * EPSILON` is a genuine
+        // 32x32->64 multiply that can't overflow.
+        let epsilon = _mm256_set1_epi64x(EPSILON as i64);
+        let hi_hi = _mm256_srli_epi64(hi, 32);
+        let hi_lo = _mm256_and_si256(hi, epsilon);
+
+        let flip = _mm256_set1_epi64x(i64::MIN);
+        let borrow = _mm256_cmpgt_epi64(_mm256_xor_si256(hi_hi, flip), _mm256_xor_si256(lo, flip));
+        let t0 = _mm256_sub_epi64(lo, hi_hi);
+        let t0 = _mm256_sub_epi64(t0, _mm256_and_si256(borrow, epsilon));
+
+        let t1 = _mm256_mul_epu32(hi_lo, epsilon);
+        Self::add_no_double_overflow(t0, t1)
+    }
+
+    #[inline]
+    unsafe fn mul(a: __m256i, b: __m256i) -> __m256i {
+        // 32x32->64 partial products, combined the schoolbook way to get each lane's full 128-bit
+        // product, then folded via `reduce128`. `mid = lo_hi + hi_lo` and `lo_lo + mid_lo` can
+        // each overflow 64 bits on their own (every term here can be close to `2^64`), so each
+        // carry is detected explicitly (unsigned result < either operand) and folded into `hi`
+        // rather than dropped by a plain `_mm256_add_epi64`.
+        let a_lo = a;
+        let a_hi = _mm256_srli_epi64(a, 32);
+        let b_lo = b;
+        let b_hi = _mm256_srli_epi64(b, 32);
+
+        let lo_lo = _mm256_mul_epu32(a_lo, b_lo);
+        let lo_hi = _mm256_mul_epu32(a_lo, b_hi);
+        let hi_lo = _mm256_mul_epu32(a_hi, b_lo);
+        let hi_hi = _mm256_mul_epu32(a_hi, b_hi);
+
+        let flip = _mm256_set1_epi64x(i64::MIN);
+        let one = _mm256_set1_epi64x(1);
+
+        let mid = _mm256_add_epi64(lo_hi, hi_lo);
+        let mid_carry = _mm256_cmpgt_epi64(_mm256_xor_si256(lo_hi, flip), _mm256_xor_si256(mid, flip));
+        let mid_lo = _mm256_slli_epi64(mid, 32);
+        let mid_hi = _mm256_add_epi64(
+            _mm256_srli_epi64(mid, 32),
+            _mm256_slli_epi64(_mm256_and_si256(mid_carry, one), 32),
+        );
+
+        let lo = _mm256_add_epi64(lo_lo, mid_lo);
+        let lo_carry = _mm256_cmpgt_epi64(_mm256_xor_si256(lo_lo, flip), _mm256_xor_si256(lo, flip));
+
+        let hi = _mm256_add_epi64(_mm256_add_epi64(hi_hi, mid_hi), _mm256_and_si256(lo_carry, one));
+        Self::reduce128(lo, hi)
+    }
+}
+
+impl Debug for PackedGoldilocksAvx2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl Default for PackedGoldilocksAvx2 {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Eq for PackedGoldilocksAvx2 {}
+impl PartialEq for PackedGoldilocksAvx2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl From<GoldilocksField> for PackedGoldilocksAvx2 {
+    fn from(x: GoldilocksField) -> Self {
+        Self::broadcast(x)
+    }
+}
+
+impl Add for PackedGoldilocksAvx2 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(unsafe { Self::add_no_double_overflow(self.0, rhs.0) })
+    }
+}
+impl AddAssign for PackedGoldilocksAvx2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl Sum for PackedGoldilocksAvx2 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl Sub for PackedGoldilocksAvx2 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+impl SubAssign for PackedGoldilocksAvx2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for PackedGoldilocksAvx2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(unsafe {
+            let order = _mm256_set1_epi64x(FIELD_ORDER as i64);
+            Self::canonicalize(_mm256_sub_epi64(order, Self::canonicalize(self.0)))
+        })
+    }
+}
+
+impl Mul for PackedGoldilocksAvx2 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(unsafe { Self::mul(self.0, rhs.0) })
+    }
+}
+impl MulAssign for PackedGoldilocksAvx2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl Product for PackedGoldilocksAvx2 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl PackedField for PackedGoldilocksAvx2 {
+    type Scalar = GoldilocksField;
+
+    const WIDTH: usize = WIDTH;
+    const ZERO: Self = Self(unsafe { core::mem::transmute([0u64; 4]) });
+    const ONE: Self = Self(unsafe { core::mem::transmute([1u64; 4]) });
+
+    fn from_slice(slice: &[GoldilocksField]) -> Self {
+        assert_eq!(slice.len(), WIDTH);
+        let words: [u64; WIDTH] = core::array::from_fn(|i| slice[i].to_canonical_u64());
+        Self(unsafe { _mm256_loadu_si256(words.as_ptr() as *const __m256i) })
+    }
+
+    fn as_slice(&self) -> &[GoldilocksField] {
+        unsafe { core::slice::from_raw_parts(&self.0 as *const __m256i as *const GoldilocksField, WIDTH) }
+    }
+
+    fn as_slice_mut(&mut self) -> &mut [GoldilocksField] {
+        unsafe { core::slice::from_raw_parts_mut(&mut self.0 as *mut __m256i as *mut GoldilocksField, WIDTH) }
+    }
+
+    fn broadcast(x: GoldilocksField) -> Self {
+        Self(unsafe { _mm256_set1_epi64x(x.to_canonical_u64() as i64) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avx2_ops_match_scalar_ops() {
+        let xs: Vec<GoldilocksField> = (0..WIDTH).map(|_| GoldilocksField::rand()).collect();
+        let ys: Vec<GoldilocksField> = (0..WIDTH).map(|_| GoldilocksField::rand()).collect();
+
+        let px = PackedGoldilocksAvx2::from_slice(&xs);
+        let py = PackedGoldilocksAvx2::from_slice(&ys);
+
+        let expected_add: Vec<_> = xs.iter().zip(&ys).map(|(&x, &y)| x + y).collect();
+        let expected_sub: Vec<_> = xs.iter().zip(&ys).map(|(&x, &y)| x - y).collect();
+        let expected_mul: Vec<_> = xs.iter().zip(&ys).map(|(&x, &y)| x * y).collect();
+
+        assert_eq!((px + py).as_slice(), expected_add.as_slice());
+        assert_eq!((px - py).as_slice(), expected_sub.as_slice());
+        assert_eq!((px * py).as_slice(), expected_mul.as_slice());
+    }
+
+    #[test]
+    fn test_avx2_reduction_near_modulus() {
+        let near_modulus = GoldilocksField::from_canonical_u64(GoldilocksField::ORDER - 1);
+        let one = GoldilocksField::ONE;
+
+        let px = PackedGoldilocksAvx2::broadcast(near_modulus);
+        let py = PackedGoldilocksAvx2::broadcast(one);
+
+        assert_eq!((px + py).as_slice(), [GoldilocksField::ZERO; WIDTH]);
+        assert_eq!((px * px).as_slice(), [near_modulus * near_modulus; WIDTH]);
+    }
+
+    #[test]
+    fn test_avx2_add_overflows_past_2_64() {
+        // `p > 2^63`, so summing two canonical values close to `p` overflows a 64-bit lane, not
+        // just the field's own modulus; `add_no_double_overflow` must fold that carry back in.
+        let near_modulus = GoldilocksField::from_canonical_u64(GoldilocksField::ORDER - 1);
+        let px = PackedGoldilocksAvx2::broadcast(near_modulus);
+
+        assert_eq!(
+            (px + px).as_slice(),
+            [near_modulus + near_modulus; WIDTH]
+        );
+    }
+}
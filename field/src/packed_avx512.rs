@@ -0,0 +1,249 @@
+//! An AVX-512 lane-of-8 [`PackedField`] backend for [`GoldilocksField`].
+//!
+//! AVX-512 has native unsigned 64-bit compares (`_mm512_cmpge_epu64_mask`) and a 52-bit multiply
+//! (`_mm512_madd52hi_epu64`/`_mm512_madd52lo_epu64`) on targets with `avx512ifma`, which makes the
+//! reduction simpler than the AVX2 backend's 32-bit-multiply workaround, but the portable
+//! `_mm512_mul_epu32`-based path below only assumes plain `avx512f`. As in the AVX2 backend,
+//! `p > 2^63`, so every partial-product add here is checked for 64-bit overflow via the native
+//! unsigned compare rather than assumed not to happen.
+#![cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+
+use core::arch::x86_64::*;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::goldilocks_field::GoldilocksField;
+use crate::packed::PackedField;
+use crate::types::Field;
+
+const WIDTH: usize = 8;
+const FIELD_ORDER: u64 = GoldilocksField::ORDER;
+const EPSILON: u64 = 0xFFFFFFFF;
+
+/// Eight `GoldilocksField` elements packed into a single `__m512i`.
+#[derive(Clone, Copy)]
+pub struct PackedGoldilocksAvx512(pub __m512i);
+
+impl PackedGoldilocksAvx512 {
+    #[inline]
+    unsafe fn canonicalize(x: __m512i) -> __m512i {
+        let order = _mm512_set1_epi64(FIELD_ORDER as i64);
+        let ge = _mm512_cmpge_epu64_mask(x, order);
+        _mm512_mask_sub_epi64(x, ge, x, order)
+    }
+
+    #[inline]
+    unsafe fn add_no_double_overflow(a: __m512i, b: __m512i) -> __m512i {
+        // `a` and `b` are each canonical (< p), but `p > 2^63`, so their sum can itself overflow
+        // 64 bits (e.g. `(p-1) + (p-1)`). Detect that wraparound with AVX-512's native unsigned
+        // compare and fold the lost `2^64` back in via `2^64 ≡ 2^32 - 1 (mod p)`.
+        let ca = Self::canonicalize(a);
+        let cb = Self::canonicalize(b);
+        let sum = _mm512_add_epi64(ca, cb);
+        let overflowed = _mm512_cmpgt_epu64_mask(ca, sum);
+        let epsilon = _mm512_set1_epi64(EPSILON as i64);
+        let sum = _mm512_mask_add_epi64(sum, overflowed, sum, epsilon);
+        Self::canonicalize(sum)
+    }
+
+    #[inline]
+    unsafe fn reduce128(lo: __m512i, hi: __m512i) -> __m512i {
+        // `lo + hi * 2^64 (mod p)`. Write `hi = hi_hi * 2^32 + hi_lo` and use `2^64 ≡ 2^32 - 1
+        // (mod p)` twice: `hi * 2^64 ≡ hi_lo * (2^32 - 1) - hi_hi (mod p)`. Unlike a plain
+        // `hi << 32`, this never discards `hi`'s top 32 bits, and `hi_lo * EPSILON` is a genuine
+        // 32x32->64 multiply that can't overflow.
+        let epsilon = _mm512_set1_epi64(EPSILON as i64);
+        let hi_hi = _mm512_srli_epi64(hi, 32);
+        let hi_lo = _mm512_and_si512(hi, epsilon);
+
+        let borrow = _mm512_cmpgt_epu64_mask(hi_hi, lo);
+        let t0 = _mm512_sub_epi64(lo, hi_hi);
+        let t0 = _mm512_mask_sub_epi64(t0, borrow, t0, epsilon);
+
+        let t1 = _mm512_mul_epu32(hi_lo, epsilon);
+        Self::add_no_double_overflow(t0, t1)
+    }
+
+    #[inline]
+    unsafe fn mul(a: __m512i, b: __m512i) -> __m512i {
+        // 32x32->64 partial products, combined the schoolbook way to get each lane's full 128-bit
+        // product, then folded via `reduce128`. `mid = lo_hi + hi_lo` and `lo_lo + mid_lo` can
+        // each overflow 64 bits on their own (every term here can be close to `2^64`), so each
+        // carry is detected explicitly with AVX-512's native unsigned compare and folded into
+        // `hi` rather than dropped by a plain `_mm512_add_epi64`.
+        let a_lo = a;
+        let a_hi = _mm512_srli_epi64(a, 32);
+        let b_lo = b;
+        let b_hi = _mm512_srli_epi64(b, 32);
+
+        let lo_lo = _mm512_mul_epu32(a_lo, b_lo);
+        let lo_hi = _mm512_mul_epu32(a_lo, b_hi);
+        let hi_lo = _mm512_mul_epu32(a_hi, b_lo);
+        let hi_hi = _mm512_mul_epu32(a_hi, b_hi);
+
+        let one = _mm512_set1_epi64(1);
+
+        let mid = _mm512_add_epi64(lo_hi, hi_lo);
+        let mid_carry = _mm512_cmpgt_epu64_mask(lo_hi, mid);
+        let mid_lo = _mm512_slli_epi64(mid, 32);
+        let mid_hi = _mm512_add_epi64(
+            _mm512_srli_epi64(mid, 32),
+            _mm512_maskz_slli_epi64(mid_carry, one, 32),
+        );
+
+        let lo = _mm512_add_epi64(lo_lo, mid_lo);
+        let lo_carry = _mm512_cmpgt_epu64_mask(lo_lo, lo);
+
+        let hi_base = _mm512_add_epi64(hi_hi, mid_hi);
+        let hi = _mm512_mask_add_epi64(hi_base, lo_carry, hi_base, one);
+        Self::reduce128(lo, hi)
+    }
+}
+
+impl Debug for PackedGoldilocksAvx512 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl Default for PackedGoldilocksAvx512 {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Eq for PackedGoldilocksAvx512 {}
+impl PartialEq for PackedGoldilocksAvx512 {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl From<GoldilocksField> for PackedGoldilocksAvx512 {
+    fn from(x: GoldilocksField) -> Self {
+        Self::broadcast(x)
+    }
+}
+
+impl Add for PackedGoldilocksAvx512 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(unsafe { Self::add_no_double_overflow(self.0, rhs.0) })
+    }
+}
+impl AddAssign for PackedGoldilocksAvx512 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl Sum for PackedGoldilocksAvx512 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl Sub for PackedGoldilocksAvx512 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+impl SubAssign for PackedGoldilocksAvx512 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for PackedGoldilocksAvx512 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(unsafe {
+            let order = _mm512_set1_epi64(FIELD_ORDER as i64);
+            Self::canonicalize(_mm512_sub_epi64(order, Self::canonicalize(self.0)))
+        })
+    }
+}
+
+impl Mul for PackedGoldilocksAvx512 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(unsafe { Self::mul(self.0, rhs.0) })
+    }
+}
+impl MulAssign for PackedGoldilocksAvx512 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl Product for PackedGoldilocksAvx512 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl PackedField for PackedGoldilocksAvx512 {
+    type Scalar = GoldilocksField;
+
+    const WIDTH: usize = WIDTH;
+    const ZERO: Self = Self(unsafe { core::mem::transmute([0u64; 8]) });
+    const ONE: Self = Self(unsafe { core::mem::transmute([1u64; 8]) });
+
+    fn from_slice(slice: &[GoldilocksField]) -> Self {
+        assert_eq!(slice.len(), WIDTH);
+        let words: [u64; WIDTH] = core::array::from_fn(|i| slice[i].to_canonical_u64());
+        Self(unsafe { _mm512_loadu_si512(words.as_ptr() as *const i32) })
+    }
+
+    fn as_slice(&self) -> &[GoldilocksField] {
+        unsafe { core::slice::from_raw_parts(&self.0 as *const __m512i as *const GoldilocksField, WIDTH) }
+    }
+
+    fn as_slice_mut(&mut self) -> &mut [GoldilocksField] {
+        unsafe { core::slice::from_raw_parts_mut(&mut self.0 as *mut __m512i as *mut GoldilocksField, WIDTH) }
+    }
+
+    fn broadcast(x: GoldilocksField) -> Self {
+        Self(unsafe { _mm512_set1_epi64(x.to_canonical_u64() as i64) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avx512_ops_match_scalar_ops() {
+        let xs: Vec<GoldilocksField> = (0..WIDTH).map(|_| GoldilocksField::rand()).collect();
+        let ys: Vec<GoldilocksField> = (0..WIDTH).map(|_| GoldilocksField::rand()).collect();
+
+        let px = PackedGoldilocksAvx512::from_slice(&xs);
+        let py = PackedGoldilocksAvx512::from_slice(&ys);
+
+        let expected_add: Vec<_> = xs.iter().zip(&ys).map(|(&x, &y)| x + y).collect();
+        let expected_mul: Vec<_> = xs.iter().zip(&ys).map(|(&x, &y)| x * y).collect();
+
+        assert_eq!((px + py).as_slice(), expected_add.as_slice());
+        assert_eq!((px * py).as_slice(), expected_mul.as_slice());
+    }
+
+    #[test]
+    fn test_avx512_add_overflows_past_2_64() {
+        // `p > 2^63`, so summing two canonical values close to `p` overflows a 64-bit lane, not
+        // just the field's own modulus; `add_no_double_overflow` must fold that carry back in.
+        let near_modulus = GoldilocksField::from_canonical_u64(GoldilocksField::ORDER - 1);
+        let px = PackedGoldilocksAvx512::broadcast(near_modulus);
+
+        assert_eq!(
+            (px + px).as_slice(),
+            [near_modulus + near_modulus; WIDTH]
+        );
+    }
+
+    #[test]
+    fn test_avx512_reduction_near_modulus() {
+        let near_modulus = GoldilocksField::from_canonical_u64(GoldilocksField::ORDER - 1);
+        let px = PackedGoldilocksAvx512::broadcast(near_modulus);
+        assert_eq!((px * px).as_slice(), [near_modulus * near_modulus; WIDTH]);
+    }
+}
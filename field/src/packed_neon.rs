@@ -0,0 +1,242 @@
+//! A NEON lane-of-2 [`PackedField`] backend for [`GoldilocksField`].
+//!
+//! AArch64 NEON has 128-bit vector registers, so a `uint64x2_t` holds exactly two Goldilocks
+//! elements. NEON has no 64x64->128 widening multiply, so each lane's full product is computed on
+//! the scalar side via `u128` and fed into the same `reduce128` the other backends use: write
+//! `hi = hi_hi * 2^32 + hi_lo` and fold it in via `2^64 ≡ 2^32 - 1 (mod p)` applied twice. `p >
+//! 2^63`, so even the plain 64-bit add of two canonical values can overflow and is checked for
+//! that carry via NEON's native unsigned compare (`vcgtq_u64`) rather than assumed not to happen.
+#![cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+
+use core::arch::aarch64::*;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::goldilocks_field::GoldilocksField;
+use crate::packed::PackedField;
+use crate::types::Field;
+
+const WIDTH: usize = 2;
+const FIELD_ORDER: u64 = GoldilocksField::ORDER;
+const EPSILON: u64 = 0xFFFFFFFF;
+
+/// Two `GoldilocksField` elements packed into a single `uint64x2_t`.
+#[derive(Clone, Copy)]
+pub struct PackedGoldilocksNeon(pub uint64x2_t);
+
+impl PackedGoldilocksNeon {
+    #[inline]
+    unsafe fn canonicalize(x: uint64x2_t) -> uint64x2_t {
+        let order = vdupq_n_u64(FIELD_ORDER);
+        let ge = vcgeq_u64(x, order);
+        vsubq_u64(x, vandq_u64(ge, order))
+    }
+
+    #[inline]
+    unsafe fn add_no_double_overflow(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        // `a` and `b` are each canonical (< p), but `p > 2^63`, so their sum can itself overflow
+        // 64 bits (e.g. `(p-1) + (p-1)`). Detect that wraparound (unsigned sum < either operand)
+        // and fold the lost `2^64` back in via `2^64 ≡ 2^32 - 1 (mod p)`.
+        let ca = Self::canonicalize(a);
+        let cb = Self::canonicalize(b);
+        let sum = vaddq_u64(ca, cb);
+        let overflowed = vcgtq_u64(ca, sum);
+        let epsilon = vdupq_n_u64(EPSILON);
+        let sum = vaddq_u64(sum, vandq_u64(overflowed, epsilon));
+        Self::canonicalize(sum)
+    }
+
+    #[inline]
+    unsafe fn reduce128(lo: uint64x2_t, hi: uint64x2_t) -> uint64x2_t {
+        // `lo + hi * 2^64 (mod p)`. Write `hi = hi_hi * 2^32 + hi_lo` and use `2^64 ≡ 2^32 - 1
+        // (mod p)` twice: `hi * 2^64 ≡ hi_lo * (2^32 - 1) - hi_hi (mod p)`. Unlike a plain
+        // `hi << 32`, this never discards `hi`'s top 32 bits, and `hi_lo * EPSILON` is a genuine
+        // 32x32->64 multiply that can't overflow.
+        let epsilon = vdupq_n_u64(EPSILON);
+        let hi_hi = vshrq_n_u64(hi, 32);
+        let hi_lo = vandq_u64(hi, epsilon);
+
+        let borrow = vcgtq_u64(hi_hi, lo);
+        let t0 = vsubq_u64(lo, hi_hi);
+        let t0 = vsubq_u64(t0, vandq_u64(borrow, epsilon));
+
+        let t1 = vmulq_u64_halves(hi_lo, epsilon);
+        Self::add_no_double_overflow(t0, t1)
+    }
+
+    #[inline]
+    unsafe fn lane_mul(a: u64, b: u64) -> (u64, u64) {
+        let product = (a as u128) * (b as u128);
+        (product as u64, (product >> 64) as u64)
+    }
+
+    #[inline]
+    unsafe fn mul(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        // NEON lacks a 64x64->128 widening multiply; compute each lane's full product on the
+        // scalar side and recombine.
+        let a_arr = core::mem::transmute::<_, [u64; 2]>(a);
+        let b_arr = core::mem::transmute::<_, [u64; 2]>(b);
+        let (lo0, hi0) = Self::lane_mul(a_arr[0], b_arr[0]);
+        let (lo1, hi1) = Self::lane_mul(a_arr[1], b_arr[1]);
+        let lo = vld1q_u64([lo0, lo1].as_ptr());
+        let hi = vld1q_u64([hi0, hi1].as_ptr());
+        Self::reduce128(lo, hi)
+    }
+}
+
+/// `hi_lo * EPSILON` as a genuine 64-bit product: both operands fit in 32 bits, so there's no
+/// widening multiply to fall back to scalar code for, just a plain per-lane `u64` multiply.
+#[inline]
+unsafe fn vmulq_u64_halves(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    let a_arr = core::mem::transmute::<_, [u64; 2]>(a);
+    let b_arr = core::mem::transmute::<_, [u64; 2]>(b);
+    vld1q_u64([a_arr[0] * b_arr[0], a_arr[1] * b_arr[1]].as_ptr())
+}
+
+impl Debug for PackedGoldilocksNeon {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl Default for PackedGoldilocksNeon {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Eq for PackedGoldilocksNeon {}
+impl PartialEq for PackedGoldilocksNeon {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl From<GoldilocksField> for PackedGoldilocksNeon {
+    fn from(x: GoldilocksField) -> Self {
+        Self::broadcast(x)
+    }
+}
+
+impl Add for PackedGoldilocksNeon {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(unsafe { Self::add_no_double_overflow(self.0, rhs.0) })
+    }
+}
+impl AddAssign for PackedGoldilocksNeon {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl Sum for PackedGoldilocksNeon {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl Sub for PackedGoldilocksNeon {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+impl SubAssign for PackedGoldilocksNeon {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for PackedGoldilocksNeon {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(unsafe {
+            let order = vdupq_n_u64(FIELD_ORDER);
+            Self::canonicalize(vsubq_u64(order, Self::canonicalize(self.0)))
+        })
+    }
+}
+
+impl Mul for PackedGoldilocksNeon {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(unsafe { Self::mul(self.0, rhs.0) })
+    }
+}
+impl MulAssign for PackedGoldilocksNeon {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl Product for PackedGoldilocksNeon {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl PackedField for PackedGoldilocksNeon {
+    type Scalar = GoldilocksField;
+
+    const WIDTH: usize = WIDTH;
+    const ZERO: Self = Self(unsafe { core::mem::transmute([0u64; 2]) });
+    const ONE: Self = Self(unsafe { core::mem::transmute([1u64; 2]) });
+
+    fn from_slice(slice: &[GoldilocksField]) -> Self {
+        assert_eq!(slice.len(), WIDTH);
+        let words: [u64; WIDTH] = core::array::from_fn(|i| slice[i].to_canonical_u64());
+        Self(unsafe { vld1q_u64(words.as_ptr()) })
+    }
+
+    fn as_slice(&self) -> &[GoldilocksField] {
+        unsafe { core::slice::from_raw_parts(&self.0 as *const uint64x2_t as *const GoldilocksField, WIDTH) }
+    }
+
+    fn as_slice_mut(&mut self) -> &mut [GoldilocksField] {
+        unsafe { core::slice::from_raw_parts_mut(&mut self.0 as *mut uint64x2_t as *mut GoldilocksField, WIDTH) }
+    }
+
+    fn broadcast(x: GoldilocksField) -> Self {
+        Self(unsafe { vdupq_n_u64(x.to_canonical_u64()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neon_ops_match_scalar_ops() {
+        let xs: Vec<GoldilocksField> = (0..WIDTH).map(|_| GoldilocksField::rand()).collect();
+        let ys: Vec<GoldilocksField> = (0..WIDTH).map(|_| GoldilocksField::rand()).collect();
+
+        let px = PackedGoldilocksNeon::from_slice(&xs);
+        let py = PackedGoldilocksNeon::from_slice(&ys);
+
+        let expected_add: Vec<_> = xs.iter().zip(&ys).map(|(&x, &y)| x + y).collect();
+        let expected_mul: Vec<_> = xs.iter().zip(&ys).map(|(&x, &y)| x * y).collect();
+
+        assert_eq!((px + py).as_slice(), expected_add.as_slice());
+        assert_eq!((px * py).as_slice(), expected_mul.as_slice());
+    }
+
+    #[test]
+    fn test_neon_add_overflows_past_2_64() {
+        // `p > 2^63`, so summing two canonical values close to `p` overflows a 64-bit lane, not
+        // just the field's own modulus; `add_no_double_overflow` must fold that carry back in.
+        let near_modulus = GoldilocksField::from_canonical_u64(GoldilocksField::ORDER - 1);
+        let px = PackedGoldilocksNeon::broadcast(near_modulus);
+
+        assert_eq!(
+            (px + px).as_slice(),
+            [near_modulus + near_modulus; WIDTH]
+        );
+    }
+
+    #[test]
+    fn test_neon_reduction_near_modulus() {
+        let near_modulus = GoldilocksField::from_canonical_u64(GoldilocksField::ORDER - 1);
+        let px = PackedGoldilocksNeon::broadcast(near_modulus);
+        assert_eq!((px * px).as_slice(), [near_modulus * near_modulus; WIDTH]);
+    }
+}
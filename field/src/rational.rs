@@ -0,0 +1,182 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::types::Field;
+
+/// A witness value that may still be a pending division, analogous to halo2's `Assigned`.
+///
+/// Generators that would otherwise perform a real field inversion the moment they fire can
+/// instead write a `Rational` into `GeneratedValues`. `Add`/`Sub`/`Mul`/`Neg` combine the
+/// fractions symbolically, so a chain of arithmetic on generator outputs accumulates a single
+/// compound denominator instead of inverting at every step. The field element is only recovered
+/// once, by [`batch_resolve`], which inverts every nonzero denominator with a single Montgomery
+/// batch inversion.
+///
+/// Scope note: this batches denominators within a single call site (its only caller today is
+/// `BatchInverseGeneratorExtension`, which already had its own local prefix-product batching and
+/// now delegates to this shared implementation instead). It is *not* a circuit-wide finalization
+/// pass that collects every generator's pending divisions into one global batch across an entire
+/// witness — that would mean threading `Rational` through `iop::generator`/`iop::witness` so
+/// every generator in a circuit defers into a shared pool, which is a larger, cross-cutting change
+/// this module doesn't attempt. `Rational`/[`batch_resolve`] are deliberately standalone and
+/// generically useful so that change, if it's still wanted, can be layered on top without
+/// reworking the fraction arithmetic itself.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rational<F: Field> {
+    /// The additive identity.
+    Zero,
+    /// A value that is already fully reduced; no division is pending.
+    Trivial(F),
+    /// A deferred `numerator / denominator`.
+    Rational(F, F),
+}
+
+impl<F: Field> Rational<F> {
+    fn as_fraction(&self) -> (F, F) {
+        match self {
+            Rational::Zero => (F::ZERO, F::ONE),
+            Rational::Trivial(x) => (*x, F::ONE),
+            Rational::Rational(num, dem) => (*num, *dem),
+        }
+    }
+
+    /// Resolves this value to a canonical field element. A denominator of exactly zero resolves
+    /// to zero, matching `div_unsafe`'s semantics.
+    pub fn resolve(&self) -> F {
+        let (num, dem) = self.as_fraction();
+        if dem.is_zero() {
+            F::ZERO
+        } else {
+            num * dem.inverse()
+        }
+    }
+}
+
+impl<F: Field> From<F> for Rational<F> {
+    fn from(x: F) -> Self {
+        Rational::Trivial(x)
+    }
+}
+
+impl<F: Field> Add for Rational<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (n0, d0) = self.as_fraction();
+        let (n1, d1) = rhs.as_fraction();
+        Rational::Rational(n0 * d1 + n1 * d0, d0 * d1)
+    }
+}
+
+impl<F: Field> Sub for Rational<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl<F: Field> Mul for Rational<F> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let (n0, d0) = self.as_fraction();
+        let (n1, d1) = rhs.as_fraction();
+        Rational::Rational(n0 * n1, d0 * d1)
+    }
+}
+
+impl<F: Field> Neg for Rational<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        match self {
+            Rational::Zero => Rational::Zero,
+            Rational::Trivial(x) => Rational::Trivial(-x),
+            Rational::Rational(num, dem) => Rational::Rational(-num, dem),
+        }
+    }
+}
+
+/// Resolves every pending [`Rational`] in `values` to its canonical field element, inverting all
+/// of their denominators with a single Montgomery batch inversion (prefix-product, one
+/// inversion, then back-substitution) rather than one inversion per value. A denominator of
+/// exactly zero resolves to zero, matching `div_unsafe`'s semantics.
+pub fn batch_resolve<F: Field>(values: &[Rational<F>]) -> Vec<F> {
+    let fractions: Vec<(F, F)> = values.iter().map(Rational::as_fraction).collect();
+    let n = fractions.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Running prefix products of the denominators, treating a zero denominator as `1` so it
+    // doesn't poison the inversion of the other entries.
+    let mut prefixes = Vec::with_capacity(n);
+    let mut acc = F::ONE;
+    for &(_, dem) in &fractions {
+        acc *= if dem.is_zero() { F::ONE } else { dem };
+        prefixes.push(acc);
+    }
+
+    let mut acc_inv = prefixes[n - 1].inverse();
+    let mut out = vec![F::ZERO; n];
+    for i in (0..n).rev() {
+        let (num, dem) = fractions[i];
+        if dem.is_zero() {
+            out[i] = F::ZERO;
+            continue;
+        }
+        let prefix_before = if i == 0 { F::ONE } else { prefixes[i - 1] };
+        out[i] = num * (prefix_before * acc_inv);
+        acc_inv *= dem;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crandall_field::CrandallField;
+
+    #[test]
+    fn test_resolve_matches_direct_division() {
+        type F = CrandallField;
+        let num = F::rand();
+        let dem = F::rand();
+        let rational = Rational::Rational(num, dem);
+        assert_eq!(rational.resolve(), num / dem);
+    }
+
+    #[test]
+    fn test_resolve_zero_denominator_is_zero() {
+        type F = CrandallField;
+        let rational: Rational<F> = Rational::Rational(F::rand(), F::ZERO);
+        assert_eq!(rational.resolve(), F::ZERO);
+    }
+
+    #[test]
+    fn test_batch_resolve_matches_individual_resolve() {
+        type F = CrandallField;
+        let values: Vec<Rational<F>> = (0..8)
+            .map(|i| {
+                if i == 3 {
+                    Rational::Rational(F::rand(), F::ZERO)
+                } else {
+                    Rational::Rational(F::rand(), F::rand())
+                }
+            })
+            .collect();
+
+        let expected: Vec<F> = values.iter().map(Rational::resolve).collect();
+        assert_eq!(batch_resolve(&values), expected);
+    }
+
+    #[test]
+    fn test_arithmetic_matches_trivial_field_arithmetic() {
+        type F = CrandallField;
+        let a = F::rand();
+        let b = F::rand();
+
+        let ra = Rational::Trivial(a);
+        let rb = Rational::Trivial(b);
+        assert_eq!((ra + rb).resolve(), a + b);
+        assert_eq!((ra - rb).resolve(), a - b);
+        assert_eq!((ra * rb).resolve(), a * b);
+        assert_eq!((-ra).resolve(), -a);
+    }
+}
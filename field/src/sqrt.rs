@@ -0,0 +1,98 @@
+use num::BigUint;
+
+use crate::ops::Square;
+use crate::types::Field;
+
+/// Adds `sqrt` to any `Field`, implemented once via a generalized Tonelli–Shanks that only needs
+/// the two pieces of data every field (and extension) here already carries: `TWO_ADICITY` and
+/// `POWER_OF_TWO_GENERATOR`, a generator of the 2-Sylow subgroup. Layered on as a trait (with a
+/// blanket impl for every `Field`) rather than added directly to `Field`'s own definition, so it
+/// reaches `GoldilocksField` and `QuadraticExtension`/`QuarticExtension`/`QuinticExtension` alike
+/// without touching that trait.
+///
+/// This is plain variable-time Tonelli–Shanks: the `while` loop below runs for a data-dependent
+/// number of iterations, and the Euler's-criterion check returns early for non-residues. Callers
+/// that need to take a square root of secret data without leaking its residue status or bit
+/// length through timing should not use this.
+pub trait Sqrt: Field {
+    /// Returns a square root of `self`, or `None` if `self` is not a quadratic residue.
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+
+        let order = Self::order();
+        let one = BigUint::from(1u32);
+
+        // Euler's criterion: `a` is a residue iff `a^((q-1)/2) == 1`.
+        if self.exp_biguint(&((&order - &one) >> 1)) == Self::NEG_ONE {
+            return None;
+        }
+
+        // Write `q - 1 = 2^e * m` with `m` odd.
+        let e = Self::TWO_ADICITY;
+        let m_exp = (&order - &one) >> e;
+
+        let mut c = Self::POWER_OF_TWO_GENERATOR.exp_biguint(&m_exp);
+        let mut t = self.exp_biguint(&m_exp);
+        let mut r = self.exp_biguint(&((&m_exp + &one) >> 1));
+        let mut m = e;
+
+        while t != Self::ONE {
+            // Find the least `i` in `1..m` with `t^(2^i) == 1`.
+            let mut i = 1;
+            let mut t2i = t.square();
+            while t2i != Self::ONE {
+                t2i = t2i.square();
+                i += 1;
+            }
+
+            let b = c.exp_power_of_2(m - i - 1);
+            m = i;
+            c = b.square();
+            t *= c;
+            r *= b;
+        }
+
+        Some(r)
+    }
+}
+
+impl<F: Field> Sqrt for F {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crandall_field::CrandallField;
+    use crate::extension::quartic::QuarticCrandallField;
+
+    #[test]
+    fn test_sqrt_round_trip_base_field() {
+        type F = CrandallField;
+        let x = F::rand();
+        let square = x * x;
+        let root = square.sqrt().expect("a square must have a root");
+        assert_eq!(root * root, square);
+    }
+
+    #[test]
+    fn test_sqrt_round_trip_extension_field() {
+        type FF = QuarticCrandallField;
+        let x = FF::rand();
+        let square = x * x;
+        let root = square.sqrt().expect("a square must have a root");
+        assert_eq!(root * root, square);
+    }
+
+    #[test]
+    fn test_sqrt_none_for_non_residue() {
+        type F = CrandallField;
+        // Exactly half of the nonzero elements are non-residues, so a handful of random samples
+        // should turn one up.
+        let non_residue = (0..32)
+            .map(|_| F::rand())
+            .find(|x| x.sqrt().is_none())
+            .expect("at least one of 32 random samples should be a non-residue");
+        assert!(non_residue.sqrt().is_none());
+    }
+}
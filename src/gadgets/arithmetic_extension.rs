@@ -1,14 +1,15 @@
 use std::convert::TryInto;
 
 use itertools::Itertools;
-use num::Integer;
 
 use crate::field::extension_field::target::{ExtensionAlgebraTarget, ExtensionTarget};
 use crate::field::extension_field::{Extendable, OEF};
 use crate::field::field_types::Field;
+use crate::field::rational::{batch_resolve, Rational};
+use crate::field::sqrt::Sqrt;
 use crate::gates::arithmetic::ArithmeticExtensionGate;
 use crate::iop::generator::{GeneratedValues, SimpleGenerator};
-use crate::iop::target::Target;
+use crate::iop::target::{BoolTarget, Target};
 use crate::iop::witness::PartialWitness;
 use crate::plonk::circuit_builder::CircuitBuilder;
 use crate::util::bits_u64;
@@ -559,6 +560,160 @@ impl<F: Extendable<D>, const D: usize> CircuitBuilder<F, D> {
 
         inv
     }
+
+    /// Computes `1 / x` for each `x` in `xs`. Equivalent to calling `inverse_extension` on each
+    /// element, but witnesses all of the inverses with a single field inversion via Montgomery's
+    /// batch-inversion trick: the generator forms running prefix products, inverts only the last
+    /// one, then walks backward to recover every individual inverse. The constraints are
+    /// unchanged from the one-at-a-time version (`x_i * inv_i == 1` for each `i`), so this is
+    /// purely a witness-generation optimization. Results in an unsatisfiable instance if any
+    /// `x_i = 0`.
+    pub fn inverse_many_extension(&mut self, xs: &[ExtensionTarget<D>]) -> Vec<ExtensionTarget<D>> {
+        let one = self.one_extension();
+        let invs: Vec<_> = xs.iter().map(|_| self.add_virtual_extension_target()).collect();
+
+        self.add_generator(BatchInverseGeneratorExtension {
+            xs: xs.to_vec(),
+            invs: invs.clone(),
+        });
+
+        for (&x, &inv) in xs.iter().zip(&invs) {
+            let x_inv = self.mul_extension(x, inv);
+            self.assert_equal_extension(x_inv, one);
+        }
+
+        invs
+    }
+
+    /// Computes `numerators[i] / denominators[i]` for each `i`, batching the denominator
+    /// inversions via `inverse_many_extension`.
+    pub fn div_many_extension(
+        &mut self,
+        numerators: &[ExtensionTarget<D>],
+        denominators: &[ExtensionTarget<D>],
+    ) -> Vec<ExtensionTarget<D>> {
+        debug_assert_eq!(numerators.len(), denominators.len());
+        let denom_invs = self.inverse_many_extension(denominators);
+        numerators
+            .iter()
+            .zip(&denom_invs)
+            .map(|(&n, &d_inv)| self.mul_extension(n, d_inv))
+            .collect()
+    }
+
+    /// Witnesses a square root `r` of `x`, along with a flag indicating whether `x` is a
+    /// quadratic residue, enforcing `r * r == x * is_square` as the only constraint.
+    ///
+    /// This is sound in the `is_square == true` direction only: a prover can otherwise always
+    /// satisfy the constraint with `is_square = false, r = 0` regardless of whether `x` is
+    /// actually a residue, since there is no in-circuit check (e.g. Euler's criterion) forcing
+    /// `is_square` to reflect reality when false. Kept private for exactly that reason — callers
+    /// should go through `sqrt_assert_square`, which forces `is_square` to true and is therefore
+    /// fully sound in both directions.
+    fn sqrt_extension(&mut self, x: ExtensionTarget<D>) -> (ExtensionTarget<D>, BoolTarget) {
+        let root = self.add_virtual_extension_target();
+        let is_square = self.add_virtual_bool_target();
+        self.add_generator(SqrtGeneratorExtension { x, root, is_square });
+
+        let is_square_ext = self.convert_to_ext(is_square.target);
+        let masked_x = self.mul_extension(x, is_square_ext);
+        let r_sq = self.square_extension(root);
+        self.assert_equal_extension(r_sq, masked_x);
+
+        (root, is_square)
+    }
+
+    /// Like `sqrt_extension`, but asserts that `x` is a quadratic residue, making the instance
+    /// unsatisfiable otherwise.
+    pub fn sqrt_assert_square(&mut self, x: ExtensionTarget<D>) -> ExtensionTarget<D> {
+        let (root, is_square) = self.sqrt_extension(x);
+        self.assert_one(is_square.target);
+        root
+    }
+
+    /// Applies the Frobenius endomorphism `x -> x^p` once.
+    pub fn frobenius_extension(&mut self, x: ExtensionTarget<D>) -> ExtensionTarget<D> {
+        self.repeated_frobenius_extension(x, 1)
+    }
+
+    /// Applies the Frobenius endomorphism `x -> x^p` `k` times, i.e. computes `x^(p^k)`. This is a
+    /// cheap linear map on `ExtensionTarget`s, since each coordinate is just scaled by a
+    /// precomputed constant power of `W`.
+    pub fn repeated_frobenius_extension(
+        &mut self,
+        x: ExtensionTarget<D>,
+        k: usize,
+    ) -> ExtensionTarget<D> {
+        x.repeated_frobenius(k, self)
+    }
+
+    /// Computes the norm `N(x) = x * Frob(x) * Frob^2(x) * ... * Frob^{D-1}(x)`, which provably
+    /// lands in the base field.
+    pub fn norm_extension(&mut self, x: ExtensionTarget<D>) -> Target {
+        let mut factors = Vec::with_capacity(D);
+        factors.push(x);
+        for k in 1..D {
+            factors.push(self.repeated_frobenius_extension(x, k));
+        }
+        let product = self.mul_many_extension(&factors);
+
+        // The norm lies in the base field; assert that the higher coordinates vanish.
+        let coeffs = product.to_target_array();
+        let zero = self.zero();
+        for &c in &coeffs[1..] {
+            self.connect(c, zero);
+        }
+        coeffs[0]
+    }
+
+    /// Computes `1 / x` using a single base-field inversion instead of a full extension
+    /// inversion. Letting `m = Frob(x) * Frob^2(x) * ... * Frob^{D-1}(x)` and `N = x * m` (which
+    /// lies in the base field, as with `norm_extension`), `1/x = m / N`. This is fully
+    /// deterministic: unlike `inverse_extension`, no extension-valued quotient is witnessed.
+    pub fn inverse_extension_via_norm(&mut self, x: ExtensionTarget<D>) -> ExtensionTarget<D> {
+        let mut factors = Vec::with_capacity(D - 1);
+        for k in 1..D {
+            factors.push(self.repeated_frobenius_extension(x, k));
+        }
+        let m = self.mul_many_extension(&factors);
+        let n_ext = self.mul_extension(x, m);
+
+        let coeffs = n_ext.to_target_array();
+        let zero = self.zero();
+        for &c in &coeffs[1..] {
+            self.connect(c, zero);
+        }
+        let n = coeffs[0];
+
+        let n_inv = self.inverse(n);
+        self.scalar_mul_ext(n_inv, m)
+    }
+}
+
+struct SqrtGeneratorExtension<const D: usize> {
+    x: ExtensionTarget<D>,
+    root: ExtensionTarget<D>,
+    is_square: BoolTarget,
+}
+
+impl<F: Extendable<D>, const D: usize> SimpleGenerator<F> for SqrtGeneratorExtension<D> {
+    fn dependencies(&self) -> Vec<Target> {
+        self.x.to_target_array().to_vec()
+    }
+
+    fn run_once(&self, witness: &PartialWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let x = witness.get_extension_target(self.x);
+        match x.sqrt() {
+            Some(root) => {
+                out_buffer.set_extension_target(self.root, root);
+                out_buffer.set_bool_target(self.is_square, true);
+            }
+            None => {
+                out_buffer.set_extension_target(self.root, F::Extension::ZERO);
+                out_buffer.set_bool_target(self.is_square, false);
+            }
+        }
+    }
 }
 
 struct QuotientGeneratorExtension<const D: usize> {
@@ -582,6 +737,43 @@ impl<F: Extendable<D>, const D: usize> SimpleGenerator<F> for QuotientGeneratorE
     }
 }
 
+/// Witnesses `1 / xs[i]` for every `i` with a single field inversion, using Montgomery's
+/// batch-inversion trick.
+struct BatchInverseGeneratorExtension<const D: usize> {
+    xs: Vec<ExtensionTarget<D>>,
+    invs: Vec<ExtensionTarget<D>>,
+}
+
+impl<F: Extendable<D>, const D: usize> SimpleGenerator<F> for BatchInverseGeneratorExtension<D> {
+    fn dependencies(&self) -> Vec<Target> {
+        self.xs
+            .iter()
+            .flat_map(|x| x.to_target_array().to_vec())
+            .collect()
+    }
+
+    fn run_once(&self, witness: &PartialWitness<F>, out_buffer: &mut GeneratedValues<F>) {
+        let xs: Vec<_> = self
+            .xs
+            .iter()
+            .map(|&x| witness.get_extension_target(x))
+            .collect();
+
+        // Defer every `1 / x_i` as a `Rational` and let `batch_resolve` do the actual Montgomery
+        // batch inversion (prefix products, one inversion, back-substitution) in one shared place
+        // instead of re-deriving it here.
+        let pending: Vec<Rational<F::Extension>> = xs
+            .iter()
+            .map(|&x| Rational::Rational(F::Extension::ONE, x))
+            .collect();
+        let invs = batch_resolve(&pending);
+
+        for (&target, inv) in self.invs.iter().zip(invs) {
+            out_buffer.set_extension_target(target, inv);
+        }
+    }
+}
+
 /// An iterator over the powers of a certain base element `b`: `b^0, b^1, b^2, ...`.
 #[derive(Clone)]
 pub struct PowersTarget<const D: usize> {
@@ -627,7 +819,9 @@ mod tests {
 
     use crate::field::crandall_field::CrandallField;
     use crate::field::extension_field::quartic::QuarticCrandallField;
+    use crate::field::extension_field::OEF;
     use crate::field::field_types::Field;
+    use crate::field::ops::Square;
     use crate::iop::witness::PartialWitness;
     use crate::plonk::circuit_builder::CircuitBuilder;
     use crate::plonk::circuit_data::CircuitConfig;
@@ -696,4 +890,141 @@ mod tests {
 
         verify(proof, &data.verifier_only, &data.common)
     }
+
+    #[test]
+    fn test_inverse_many_extension() -> Result<()> {
+        type F = CrandallField;
+        type FF = QuarticCrandallField;
+        const D: usize = 4;
+
+        let config = CircuitConfig::large_config();
+
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let mut pw = PartialWitness::new();
+
+        let vs = FF::rand_vec(5);
+        let ts = builder.add_virtual_extension_targets(5);
+        for (&v, &t) in vs.iter().zip(&ts) {
+            pw.set_extension_target(t, v);
+        }
+
+        let invs = builder.inverse_many_extension(&ts);
+        for (&v, &inv) in vs.iter().zip(&invs) {
+            let expected = builder.constant_extension(v.inverse());
+            builder.assert_equal_extension(inv, expected);
+        }
+
+        let data = builder.build();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
+
+    #[test]
+    fn test_div_many_extension() -> Result<()> {
+        type F = CrandallField;
+        type FF = QuarticCrandallField;
+        const D: usize = 4;
+
+        let config = CircuitConfig::large_config();
+
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let mut pw = PartialWitness::new();
+
+        let nums = FF::rand_vec(4);
+        let dems = FF::rand_vec(4);
+        let num_ts = builder.add_virtual_extension_targets(4);
+        let dem_ts = builder.add_virtual_extension_targets(4);
+        for ((&n, &nt), (&d, &dt)) in nums.iter().zip(&num_ts).zip(dems.iter().zip(&dem_ts)) {
+            pw.set_extension_target(nt, n);
+            pw.set_extension_target(dt, d);
+        }
+
+        let quotients = builder.div_many_extension(&num_ts, &dem_ts);
+        for ((&n, &d), &q) in nums.iter().zip(&dems).zip(&quotients) {
+            let expected = builder.constant_extension(n / d);
+            builder.assert_equal_extension(q, expected);
+        }
+
+        let data = builder.build();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
+
+    #[test]
+    fn test_sqrt_extension_of_a_square() -> Result<()> {
+        type F = CrandallField;
+        type FF = QuarticCrandallField;
+        const D: usize = 4;
+
+        let config = CircuitConfig::large_config();
+
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let mut pw = PartialWitness::new();
+
+        // Squaring guarantees a quadratic residue.
+        let x = FF::rand().square();
+        let xt = builder.add_virtual_extension_target();
+        pw.set_extension_target(xt, x);
+
+        let root = builder.sqrt_assert_square(xt);
+        let root_sq = builder.square_extension(root);
+        builder.assert_equal_extension(root_sq, xt);
+
+        let data = builder.build();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
+
+    #[test]
+    fn test_norm_extension() -> Result<()> {
+        type F = CrandallField;
+        type FF = QuarticCrandallField;
+        const D: usize = 4;
+
+        let config = CircuitConfig::large_config();
+
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let mut pw = PartialWitness::new();
+
+        let x = FF::rand();
+        let xt = builder.add_virtual_extension_target();
+        pw.set_extension_target(xt, x);
+
+        let norm = builder.norm_extension(xt);
+        let expected = builder.constant(x.norm());
+        builder.connect(norm, expected);
+
+        let data = builder.build();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
+
+    #[test]
+    fn test_inverse_extension_via_norm() -> Result<()> {
+        type F = CrandallField;
+        type FF = QuarticCrandallField;
+        const D: usize = 4;
+
+        let config = CircuitConfig::large_config();
+
+        let mut builder = CircuitBuilder::<F, D>::new(config);
+        let mut pw = PartialWitness::new();
+
+        let x = FF::rand();
+        let xt = builder.add_virtual_extension_target();
+        pw.set_extension_target(xt, x);
+
+        let inv = builder.inverse_extension_via_norm(xt);
+        let expected = builder.constant_extension(x.inverse());
+        builder.assert_equal_extension(inv, expected);
+
+        let data = builder.build();
+        let proof = data.prove(pw)?;
+
+        verify(proof, &data.verifier_only, &data.common)
+    }
 }